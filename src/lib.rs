@@ -21,6 +21,8 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
 pub enum DesktopEnvironment {
+  /// Android, Google's mobile operating system.
+  Android,
   /// Cinnamon, the default desktop environment for Linux Mint.
   ///
   /// - <https://en.wikipedia.org/wiki/Cinnamon_(desktop_environment)>
@@ -57,6 +59,12 @@ pub enum DesktopEnvironment {
   ///
   /// - <https://hyprland.org/>
   Hyprland,
+  /// i3, a tiling window manager.
+  ///
+  /// - <https://i3wm.org/>
+  I3,
+  /// iOS, Apple's mobile operating system.
+  Ios,
   /// KDE Plasma, the Kool Desktop Environment.
   ///
   /// - <https://kde.org/plasma-desktop/>
@@ -80,6 +88,10 @@ pub enum DesktopEnvironment {
   /// Listed in [Freedesktop Desktop Environments](https://specifications.freedesktop.org/menu-spec/latest/apb.html).
   // Please send a PR if you have more details or better ideas about how to handle this value.
   Old,
+  /// Openbox, a stacking window manager.
+  ///
+  /// - <https://openbox.org/>
+  Openbox,
   /// Elementary OS Desktop Environment
   ///
   /// - <https://elementary.io/>
@@ -107,6 +119,8 @@ pub enum DesktopEnvironment {
   ///
   /// - <https://en.wikipedia.org/wiki/Unity_%28user_interface%29>
   Unity,
+  /// A web browser, when running as a WebAssembly target (`wasm32`).
+  WebBrowser,
   /// Windows, the environments for Microsoft's OS
   Windows,
   /// Xfce
@@ -123,6 +137,54 @@ impl DesktopEnvironment {
     Self::detect_impl()
   }
 
+  /// Detect every desktop environment active in the current session, in priority order.
+  ///
+  /// Unlike [`DesktopEnvironment::detect`], this does not give up when `XDG_CURRENT_DESKTOP`
+  /// contains multiple recognized desktop environments (e.g. `GNOME:Unity`): every one found is
+  /// returned, most-specific first, letting the caller apply its own precedence policy.
+  pub fn detect_all() -> Vec<Self> {
+    Self::detect_impl_all()
+  }
+
+  /// Detect the major version of the current desktop environment.
+  ///
+  /// This is currently only supported for [`Kde`](Self::Kde) (via `KDE_SESSION_VERSION`) and
+  /// [`Gnome`](Self::Gnome) (via `gnome-shell --version`).
+  ///
+  /// Returns `None` if the desktop environment can't be detected, or if its version can't be
+  /// determined.
+  pub fn detect_version() -> Option<u32> {
+    match Self::detect()? {
+      DesktopEnvironment::Kde => {
+        std::env::var("KDE_SESSION_VERSION").ok().as_deref().and_then(Self::parse_kde_session_version)
+      }
+      DesktopEnvironment::Gnome => Self::detect_gnome_version(),
+      _ => None,
+    }
+  }
+
+  /// Parse the `KDE_SESSION_VERSION` environment variable into a major version number.
+  fn parse_kde_session_version(raw: &str) -> Option<u32> {
+    raw.trim().parse().ok()
+  }
+
+  /// Detect the major version of a running GNOME Shell session.
+  ///
+  /// `GNOME_SHELL_SESSION_MODE` doesn't carry version information itself, but its presence
+  /// confirms that a GNOME Shell session is running, which lets us reliably shell out to
+  /// `gnome-shell --version` to retrieve it.
+  fn detect_gnome_version() -> Option<u32> {
+    std::env::var_os("GNOME_SHELL_SESSION_MODE")?;
+    let output = std::process::Command::new("gnome-shell").arg("--version").output().ok()?;
+    Self::parse_gnome_shell_version(&String::from_utf8(output.stdout).ok()?)
+  }
+
+  /// Parse the output of `gnome-shell --version` (e.g. `"GNOME Shell 45.2\n"`) into a major
+  /// version number.
+  fn parse_gnome_shell_version(raw: &str) -> Option<u32> {
+    raw.split_whitespace().last()?.split('.').next()?.parse().ok()
+  }
+
   /// Test if the desktop environment is based on the GTK framework
   ///
   /// See <https://en.wikipedia.org/wiki/Category:Desktop_environments_based_on_GTK>
@@ -144,6 +206,8 @@ impl DesktopEnvironment {
   /// // Non-GTK examples
   /// assert!(!DesktopEnvironment::Kde.gtk());
   /// assert!(!DesktopEnvironment::Windows.gtk());
+  /// assert!(!DesktopEnvironment::Openbox.gtk());
+  /// assert!(!DesktopEnvironment::Android.gtk());
   /// ```
   pub const fn gtk(self) -> bool {
     use DesktopEnvironment::*;
@@ -164,6 +228,8 @@ impl DesktopEnvironment {
   /// // Non-Qt examples
   /// assert!(!DesktopEnvironment::Gnome.qt());
   /// assert!(!DesktopEnvironment::Windows.qt());
+  /// assert!(!DesktopEnvironment::I3.qt());
+  /// assert!(!DesktopEnvironment::Ios.qt());
   /// ```
   pub const fn qt(self) -> bool {
     use DesktopEnvironment::*;
@@ -175,14 +241,165 @@ impl DesktopEnvironment {
     Some(DesktopEnvironment::MacOs)
   }
 
+  #[cfg(target_os = "macos")]
+  fn detect_impl_all() -> Vec<Self> {
+    vec![DesktopEnvironment::MacOs]
+  }
+
   #[cfg(target_os = "windows")]
   fn detect_impl() -> Option<Self> {
     Some(DesktopEnvironment::Windows)
   }
 
-  #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+  #[cfg(target_os = "windows")]
+  fn detect_impl_all() -> Vec<Self> {
+    vec![DesktopEnvironment::Windows]
+  }
+
+  #[cfg(target_os = "android")]
+  fn detect_impl() -> Option<Self> {
+    Some(DesktopEnvironment::Android)
+  }
+
+  #[cfg(target_os = "android")]
+  fn detect_impl_all() -> Vec<Self> {
+    vec![DesktopEnvironment::Android]
+  }
+
+  #[cfg(target_os = "ios")]
+  fn detect_impl() -> Option<Self> {
+    Some(DesktopEnvironment::Ios)
+  }
+
+  #[cfg(target_os = "ios")]
+  fn detect_impl_all() -> Vec<Self> {
+    vec![DesktopEnvironment::Ios]
+  }
+
+  #[cfg(target_arch = "wasm32")]
   fn detect_impl() -> Option<Self> {
-    std::env::var("XDG_CURRENT_DESKTOP").ok().as_deref().and_then(Self::from_xdg_current_desktop)
+    Some(DesktopEnvironment::WebBrowser)
+  }
+
+  #[cfg(target_arch = "wasm32")]
+  fn detect_impl_all() -> Vec<Self> {
+    vec![DesktopEnvironment::WebBrowser]
+  }
+
+  #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+  fn detect_impl() -> Option<Self> {
+    if let Some(de) = std::env::var("XDG_CURRENT_DESKTOP").ok().as_deref().and_then(Self::from_xdg_current_desktop) {
+      let desktop_session = std::env::var("DESKTOP_SESSION").ok();
+      return Some(Self::resolve_gnome_fallback(de, desktop_session.as_deref()));
+    }
+    // `XDG_CURRENT_DESKTOP` is the authoritative source, but it's frequently absent (TTY logins,
+    // minimal distros, some display managers). Fall back to the less standardized variables below,
+    // matching their names case-insensitively since they aren't governed by the XDG spec.
+    if let Some(de) = std::env::var("XDG_SESSION_DESKTOP").ok().as_deref().and_then(Self::from_xdg_name_lossy) {
+      return Some(de);
+    }
+    if let Some(de) = std::env::var("DESKTOP_SESSION")
+      .ok()
+      .as_deref()
+      .and_then(|session| session.rsplit('/').next())
+      .and_then(Self::from_xdg_name_lossy)
+    {
+      return Some(de);
+    }
+    Self::detect_window_manager()
+  }
+
+  #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+  fn detect_impl_all() -> Vec<Self> {
+    let all = std::env::var("XDG_CURRENT_DESKTOP").ok().as_deref().map(Self::from_xdg_current_desktop_all).unwrap_or_default();
+    if !all.is_empty() {
+      let desktop_session = std::env::var("DESKTOP_SESSION").ok();
+      let mut resolved: Vec<Self> = Vec::with_capacity(all.len());
+      for de in all {
+        let de = Self::resolve_gnome_fallback(de, desktop_session.as_deref());
+        // Resolving the gnome-fallback correction can collapse two distinct entries (e.g. `Unity`
+        // and `Gnome`) into the same one, so re-check for duplicates here.
+        if !resolved.contains(&de) {
+          resolved.push(de);
+        }
+      }
+      return resolved;
+    }
+    // `XDG_CURRENT_DESKTOP` didn't resolve to anything recognized; fall back to the same chain as
+    // `detect_impl`, so the two APIs agree on what's detectable.
+    Self::detect_impl().into_iter().collect()
+  }
+
+  /// Disambiguate GNOME Flashback (aka gnome-fallback) sessions from real Unity sessions.
+  ///
+  /// GNOME Flashback sets `XDG_CURRENT_DESKTOP=Unity` even though it is really GNOME; the only way
+  /// to tell the two apart is cross-referencing `DESKTOP_SESSION`, which contains `gnome-fallback`
+  /// for these sessions. This needs a second variable, so it belongs here in the detection layer
+  /// rather than in the pure [`DesktopEnvironment::from_xdg_name`] parser; it's kept as a separate
+  /// helper taking both already-parsed values so it stays unit-testable.
+  #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+  fn resolve_gnome_fallback(de: Self, desktop_session: Option<&str>) -> Self {
+    match (de, desktop_session) {
+      (DesktopEnvironment::Unity, Some(desktop_session)) if desktop_session.contains("gnome-fallback") => {
+        DesktopEnvironment::Gnome
+      }
+      _ => de,
+    }
+  }
+
+  /// Case-insensitive variant of [`DesktopEnvironment::from_xdg_name`].
+  ///
+  /// `XDG_SESSION_DESKTOP` and `DESKTOP_SESSION` are not governed by the XDG spec and are known to
+  /// use inconsistent casing (e.g. `plasma` instead of `KDE`), so they need a looser match than the
+  /// strict `XDG_CURRENT_DESKTOP` parser.
+  #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+  fn from_xdg_name_lossy(name: &str) -> Option<Self> {
+    const NAMES: &[(&str, DesktopEnvironment)] = &[
+      ("GNOME", DesktopEnvironment::Gnome),
+      ("GNOME-CLASSIC", DesktopEnvironment::Gnome),
+      ("GNOME-FLASHBACK", DesktopEnvironment::Gnome),
+      ("KDE", DesktopEnvironment::Kde),
+      ("PLASMA", DesktopEnvironment::Kde),
+      ("LXDE", DesktopEnvironment::Lxde),
+      ("LXQT", DesktopEnvironment::Lxqt),
+      ("MATE", DesktopEnvironment::Mate),
+      ("RAZOR", DesktopEnvironment::Razor),
+      ("ROX", DesktopEnvironment::Rox),
+      ("TDE", DesktopEnvironment::Tde),
+      ("UNITY", DesktopEnvironment::Unity),
+      ("XFCE", DesktopEnvironment::Xfce),
+      ("EDE", DesktopEnvironment::Ede),
+      ("CINNAMON", DesktopEnvironment::Cinnamon),
+      ("X-CINNAMON", DesktopEnvironment::Cinnamon),
+      ("PANTHEON", DesktopEnvironment::Pantheon),
+      ("DDE", DesktopEnvironment::Dde),
+      ("ENDLESS", DesktopEnvironment::Endless),
+      ("OLD", DesktopEnvironment::Old),
+      ("ENLIGHTENMENT", DesktopEnvironment::Enlightenment),
+      ("HYPRLAND", DesktopEnvironment::Hyprland),
+      ("I3", DesktopEnvironment::I3),
+      ("OPENBOX", DesktopEnvironment::Openbox),
+      ("SWAY", DesktopEnvironment::Sway),
+    ];
+    NAMES.iter().find(|(candidate, _)| name.eq_ignore_ascii_case(candidate)).map(|(_, de)| *de)
+  }
+
+  /// Recognize a bare window manager from environment variables set by its IPC protocol.
+  ///
+  /// This is a last resort used when no desktop session variable is set at all. It currently only
+  /// covers compositors that advertise themselves this way; recognizing arbitrary X11 window
+  /// managers would require querying the `_NET_SUPPORTING_WM_CHECK` / `_NET_WM_NAME` properties,
+  /// which needs an X11 client library. This crate intentionally has zero dependencies, so that is
+  /// left out for now. Please send a PR if you have a lightweight way to do this.
+  #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+  fn detect_window_manager() -> Option<Self> {
+    if std::env::var_os("SWAYSOCK").is_some() {
+      return Some(DesktopEnvironment::Sway);
+    }
+    if std::env::var_os("HYPRLAND_INSTANCE_SIGNATURE").is_some() {
+      return Some(DesktopEnvironment::Hyprland);
+    }
+    None
   }
 
   /// Parse the desktop environment from the name registered with Freedesktop.org
@@ -253,6 +470,8 @@ impl DesktopEnvironment {
     match name {
       "ENLIGHTENMENT" => Some(DesktopEnvironment::Enlightenment),
       "Hyprland" => Some(DesktopEnvironment::Hyprland),
+      "i3" => Some(DesktopEnvironment::I3),
+      "Openbox" => Some(DesktopEnvironment::Openbox),
       "SWAY" => Some(DesktopEnvironment::Sway),
       "X-Cinnamon" => Some(DesktopEnvironment::Cinnamon),
       _ => None,
@@ -299,11 +518,68 @@ impl DesktopEnvironment {
 
     resolved
   }
+
+  /// Retrieve the desktop environment from `XDG_CURRENT_DESKTOP`, preferring the first recognized
+  /// entry over rejecting conflicts.
+  ///
+  /// `XDG_CURRENT_DESKTOP` is a colon separated list ordered most-specific-first, so unlike
+  /// [`DesktopEnvironment::from_xdg_current_desktop`] (which returns `None` as soon as two
+  /// different recognized desktop environments appear, e.g. `KDE:GNOME`), this walks the list left
+  /// to right and returns the first entry it recognizes, ignoring the rest.
+  ///
+  /// Returns `None` if no part of the list is recognized.
+  pub fn from_xdg_current_desktop_priority(xdg_current_desktop: &str) -> Option<Self> {
+    xdg_current_desktop.split(':').find_map(Self::from_xdg_name)
+  }
+
+  /// Retrieve every recognized desktop environment from the format used by `XDG_CURRENT_DESKTOP`.
+  ///
+  /// `XDG_CURRENT_DESKTOP` is a colon separated list ordered most-specific-first. Unlike
+  /// [`DesktopEnvironment::from_xdg_current_desktop`], this does not reject the list when it
+  /// contains several recognized desktop environments: every one found is returned, in order,
+  /// with duplicates removed. This lets callers implement their own precedence policy instead of
+  /// being forced into the conflict rejection of [`DesktopEnvironment::from_xdg_current_desktop`].
+  pub fn from_xdg_current_desktop_all(xdg_current_desktop: &str) -> Vec<Self> {
+    let mut resolved: Vec<DesktopEnvironment> = Vec::new();
+
+    for part in xdg_current_desktop.split(':') {
+      if let Some(de) = Self::from_xdg_name(part) {
+        if !resolved.contains(&de) {
+          resolved.push(de);
+        }
+      }
+    }
+
+    resolved
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use std::sync::Mutex;
+
+  static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+  /// Clears `vars`, runs `f`, then restores their original values.
+  ///
+  /// Tests run in parallel threads within the same process, so reading/writing real environment
+  /// variables needs to be serialized via `ENV_LOCK` to avoid cross-test interference.
+  #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+  fn with_clean_env(vars: &[&str], f: impl FnOnce()) {
+    let _guard = ENV_LOCK.lock().unwrap();
+    let saved: Vec<(&str, Option<String>)> = vars.iter().map(|&var| (var, std::env::var(var).ok())).collect();
+    for &var in vars {
+      std::env::remove_var(var);
+    }
+    f();
+    for (var, value) in saved {
+      match value {
+        Some(value) => std::env::set_var(var, value),
+        None => std::env::remove_var(var),
+      }
+    }
+  }
 
   #[test]
   fn linux_tests() {
@@ -368,6 +644,14 @@ mod tests {
       DesktopEnvironment::from_xdg_current_desktop("Hyprland"),
       Some(DesktopEnvironment::Hyprland)
     );
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop("i3"),
+      Some(DesktopEnvironment::I3)
+    );
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop("Openbox"),
+      Some(DesktopEnvironment::Openbox)
+    );
 
     // Colon splitting
     assert_eq!(
@@ -411,4 +695,166 @@ mod tests {
       None
     );
   }
+
+  #[test]
+  fn from_xdg_name_lossy_tests() {
+    // Case-insensitive match
+    assert_eq!(
+      DesktopEnvironment::from_xdg_name_lossy("plasma"),
+      Some(DesktopEnvironment::Kde)
+    );
+    assert_eq!(
+      DesktopEnvironment::from_xdg_name_lossy("gnome"),
+      Some(DesktopEnvironment::Gnome)
+    );
+    assert_eq!(
+      DesktopEnvironment::from_xdg_name_lossy("SWAY"),
+      Some(DesktopEnvironment::Sway)
+    );
+
+    // Unknown name
+    assert_eq!(DesktopEnvironment::from_xdg_name_lossy("unknown_de"), None);
+  }
+
+  #[test]
+  fn from_xdg_current_desktop_priority_tests() {
+    // Single recognized DE
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop_priority("GNOME"),
+      Some(DesktopEnvironment::Gnome)
+    );
+
+    // Unrecognized parts are skipped
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop_priority("ubuntu:GNOME"),
+      Some(DesktopEnvironment::Gnome)
+    );
+
+    // Same DE repeated under different registered names isn't a real conflict
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop_priority("GNOME:GNOME-Classic"),
+      Some(DesktopEnvironment::Gnome)
+    );
+
+    // Genuine conflicts resolve to the first recognized entry instead of `None`
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop_priority("KDE:GNOME"),
+      Some(DesktopEnvironment::Kde)
+    );
+
+    // Empty string
+    assert_eq!(DesktopEnvironment::from_xdg_current_desktop_priority(""), None);
+
+    // Unknown Desktop Environment
+    assert_eq!(DesktopEnvironment::from_xdg_current_desktop_priority("foo"), None);
+  }
+
+  #[test]
+  fn from_xdg_current_desktop_all_tests() {
+    // Single recognized DE
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop_all("GNOME"),
+      vec![DesktopEnvironment::Gnome]
+    );
+
+    // Unrecognized parts are skipped
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop_all("ubuntu:GNOME"),
+      vec![DesktopEnvironment::Gnome]
+    );
+
+    // Conflicting entries are all returned, in order, instead of being rejected
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop_all("GNOME:Unity"),
+      vec![DesktopEnvironment::Gnome, DesktopEnvironment::Unity]
+    );
+
+    // Duplicates are removed
+    assert_eq!(
+      DesktopEnvironment::from_xdg_current_desktop_all("GNOME:GNOME"),
+      vec![DesktopEnvironment::Gnome]
+    );
+
+    // Empty string
+    assert_eq!(DesktopEnvironment::from_xdg_current_desktop_all(""), Vec::new());
+  }
+
+  #[test]
+  fn resolve_gnome_fallback_tests() {
+    // gnome-fallback session reporting Unity is corrected to GNOME
+    assert_eq!(
+      DesktopEnvironment::resolve_gnome_fallback(DesktopEnvironment::Unity, Some("gnome-fallback")),
+      DesktopEnvironment::Gnome
+    );
+    assert_eq!(
+      DesktopEnvironment::resolve_gnome_fallback(DesktopEnvironment::Unity, Some("gnome-fallback-compiz")),
+      DesktopEnvironment::Gnome
+    );
+
+    // A real Unity session is left untouched
+    assert_eq!(
+      DesktopEnvironment::resolve_gnome_fallback(DesktopEnvironment::Unity, Some("ubuntu")),
+      DesktopEnvironment::Unity
+    );
+    assert_eq!(DesktopEnvironment::resolve_gnome_fallback(DesktopEnvironment::Unity, None), DesktopEnvironment::Unity);
+
+    // Other desktop environments are never affected
+    assert_eq!(
+      DesktopEnvironment::resolve_gnome_fallback(DesktopEnvironment::Gnome, Some("gnome-fallback")),
+      DesktopEnvironment::Gnome
+    );
+  }
+
+  #[test]
+  fn parse_kde_session_version_tests() {
+    assert_eq!(DesktopEnvironment::parse_kde_session_version("5"), Some(5));
+    assert_eq!(DesktopEnvironment::parse_kde_session_version("6\n"), Some(6));
+    assert_eq!(DesktopEnvironment::parse_kde_session_version(""), None);
+    assert_eq!(DesktopEnvironment::parse_kde_session_version("unknown"), None);
+  }
+
+  #[test]
+  fn parse_gnome_shell_version_tests() {
+    assert_eq!(DesktopEnvironment::parse_gnome_shell_version("GNOME Shell 45.2\n"), Some(45));
+    assert_eq!(DesktopEnvironment::parse_gnome_shell_version("GNOME Shell 3.38.5"), Some(3));
+    assert_eq!(DesktopEnvironment::parse_gnome_shell_version(""), None);
+  }
+
+  #[test]
+  #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+  fn detect_impl_all_falls_back_to_window_manager_tests() {
+    with_clean_env(
+      &["XDG_CURRENT_DESKTOP", "XDG_SESSION_DESKTOP", "DESKTOP_SESSION", "SWAYSOCK", "HYPRLAND_INSTANCE_SIGNATURE"],
+      || {
+        // `detect_impl_all` must agree with `detect_impl`: neither XDG variable is set, but
+        // `SWAYSOCK` reveals a running Sway session.
+        std::env::set_var("SWAYSOCK", "/run/user/1000/sway-ipc.sock");
+        assert_eq!(DesktopEnvironment::detect_impl_all(), vec![DesktopEnvironment::Sway]);
+      },
+    );
+  }
+
+  #[test]
+  #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+  fn detect_impl_all_applies_gnome_fallback_tests() {
+    with_clean_env(&["XDG_CURRENT_DESKTOP", "DESKTOP_SESSION"], || {
+      // `detect_impl_all` must agree with `detect_impl`: a gnome-fallback session reporting
+      // `Unity` resolves to `Gnome`.
+      std::env::set_var("XDG_CURRENT_DESKTOP", "Unity");
+      std::env::set_var("DESKTOP_SESSION", "gnome-fallback");
+      assert_eq!(DesktopEnvironment::detect_impl_all(), vec![DesktopEnvironment::Gnome]);
+    });
+  }
+
+  #[test]
+  #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "android", target_os = "ios", target_arch = "wasm32")))]
+  fn detect_impl_all_dedupes_after_gnome_fallback_tests() {
+    with_clean_env(&["XDG_CURRENT_DESKTOP", "DESKTOP_SESSION"], || {
+      // `Unity` and `GNOME` are distinct entries until the gnome-fallback correction collapses
+      // `Unity` into `Gnome`, at which point the result must be deduped again.
+      std::env::set_var("XDG_CURRENT_DESKTOP", "Unity:GNOME");
+      std::env::set_var("DESKTOP_SESSION", "gnome-fallback");
+      assert_eq!(DesktopEnvironment::detect_impl_all(), vec![DesktopEnvironment::Gnome]);
+    });
+  }
 }